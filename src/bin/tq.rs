@@ -1,5 +1,5 @@
 use clap::Parser;
-use std::{fs::File, io::{self, Read}, path::PathBuf};
+use std::{env, fs::File, io::{self, IsTerminal, Read}, path::PathBuf};
 use toml::Value;
 
 #[derive(Parser, Debug)]
@@ -22,12 +22,43 @@ struct Cli {
     #[arg(short, long)]
     pub pretty: bool,
 
-    /// Field to read from the TOML file
-    pub pattern: String,
+    /// How `--output html` marks up its spans: `classes` emits semantic class names
+    /// (`key`, `string`, `integer`, ...) for an external stylesheet to target, `inline`
+    /// writes `style` attributes directly onto each `<span>`.
+    #[arg(long, default_value = "classes")]
+    pub html_markup: HtmlMarkup,
 
-    #[cfg(feature = "syntax-highlighting")]
+    /// Field to read from the TOML file. Required unless `--list-themes` is given.
+    #[cfg_attr(feature = "syntax-highlighting", arg(required_unless_present = "list_themes"))]
+    #[cfg_attr(not(feature = "syntax-highlighting"), arg(required = true))]
+    pub pattern: Option<String>,
+
+    /// When to color diagnostic/highlighted output. `auto` (default) colors each stream
+    /// (stdout, stderr) when that stream is a terminal, honoring `NO_COLOR`, `CLICOLOR`, and `CLICOLOR_FORCE`; `always`/`never`
+    /// force the decision regardless of environment or TTY.
     #[arg(short, long, default_value = "auto")]
-    pub color: clap::ColorChoice
+    pub color: clap::ColorChoice,
+
+    /// The `bat` theme to highlight with. Defaults to the `TOMLQ_THEME` or `BAT_THEME`
+    /// environment variable, falling back to `bat`'s own default if neither is set.
+    #[cfg(feature = "syntax-highlighting")]
+    #[arg(long)]
+    pub theme: Option<String>,
+
+    /// List the available `--theme` names and exit.
+    #[cfg(feature = "syntax-highlighting")]
+    #[arg(long)]
+    pub list_themes: bool,
+
+    /// An `element=style` override for the built-in colorizer, e.g. `key=bold fg:cyan`.
+    /// Styles use the starship style grammar (`bold`, `italic`, `underline`, `dimmed`,
+    /// `fg:<color>`, `bg:<color>`, where `<color>` is a name, `#rrggbb`, or a 0-255 ANSI
+    /// code). Can be passed multiple times. Elements: `key`, `string`, `integer`, `float`,
+    /// `boolean`, `datetime`, `table`. Defaults can also be set via `TOMLQ_COLORS`, a
+    /// comma-separated list of the same `element=style` pairs.
+    #[cfg(feature = "lite-highlight")]
+    #[arg(long = "highlight", value_name = "ELEMENT=STYLE")]
+    pub highlight: Vec<String>,
 }
 
 #[derive(Default, Debug, Copy, Clone, clap::ValueEnum)]
@@ -37,18 +68,37 @@ enum Format {
 
     #[cfg(feature = "json")]
     Json,
+
+    /// An HTML fragment, suitable for embedding in docs or web pages.
+    Html,
+}
+
+#[derive(Default, Debug, Copy, Clone, clap::ValueEnum)]
+enum HtmlMarkup {
+    /// Semantic class names (`key`, `string`, `integer`, ...) for an external stylesheet.
+    #[default]
+    Classes,
+
+    /// Inline `style` attributes baked directly into each `<span>`.
+    Inline,
 }
 
 fn main() -> anyhow::Result<()> {
     let app = Cli::parse();
 
+    // Resolve --color plus NO_COLOR/CLICOLOR/CLICOLOR_FORCE into a decision per stream, and
+    // share the stdout one with `bat`'s printer so piping output never disagrees with ours.
+    set_colors_enabled(resolve_colors(app.color, io::stdout().is_terminal()));
+    set_stderr_colors_enabled(resolve_colors(app.color, io::stderr().is_terminal()));
+    #[cfg(feature = "syntax-highlighting")]
+    console::set_colors_enabled(colors_enabled());
+
     #[cfg(feature = "syntax-highlighting")]
-    match app.color {
-        // console will by default respect certain environment variables for color output, 
-        // in addition to checking if the standard output is a TTY.
-        clap::ColorChoice::Auto => {},
-        clap::ColorChoice::Never => console::set_colors_enabled(false),
-        clap::ColorChoice::Always => console::set_colors_enabled(true),
+    if app.list_themes {
+        for theme in bat::assets::HighlightingAssets::from_binary().themes() {
+            println!("{theme}");
+        }
+        return Ok(());
     }
 
     // Get a reader over the input to tq.
@@ -71,9 +121,25 @@ fn main() -> anyhow::Result<()> {
                 input_string
             },
     };
-    let toml_value: toml::Value = toml::from_str(&input_string)?;
+    let toml_value: toml::Value = match toml::from_str(&input_string) {
+        Ok(value) => value,
+        Err(err) => {
+            report_parse_error(&input_string, &err);
+            std::process::exit(1);
+        }
+    };
+
+    // clap guarantees this is `Some` by now: `--list-themes` already returned above, and
+    // otherwise `pattern` is required (see its `#[arg(...)]` on `Cli`).
+    let pattern = app.pattern.as_deref().expect("pattern is required unless --list-themes is set");
 
-    let result: &Value = tq::extract_pattern(&toml_value, &app.pattern)?;
+    let result: &Value = match tq::extract_pattern(&toml_value, pattern) {
+        Ok(value) => value,
+        Err(err) => {
+            report_pattern_error(&toml_value, pattern, &err);
+            std::process::exit(1);
+        }
+    };
 
     // Generate a string to print
     let output = match (app.output, app.pretty) {
@@ -85,19 +151,36 @@ fn main() -> anyhow::Result<()> {
 
         #[cfg(feature = "json")]
         (Format::Json, true) => serde_json::to_string_pretty(result)?,
+
+        (Format::Html, _) => render_html(result, app.html_markup),
     };
 
+    // HTML is always a self-contained fragment, so it bypasses both --pretty and the
+    // syntax-highlighting printer below.
+    if matches!(app.output, Format::Html) {
+        println!("{output}");
+        return Ok(());
+    }
+
     #[cfg(feature = "syntax-highlighting")] {
         // If the syntax-highlighting crate feature is enabled, use `bat`'s pretty printing system to print with 
         // highlighting. This will not restructure code/lines, and does not override the --pretty flag.
         let mut pretty_printer = bat::PrettyPrinter::new();
 
         pretty_printer
-            .colored_output(console::colors_enabled())
+            .colored_output(colors_enabled())
             .grid(false)
             .rule(false)
             .line_numbers(false);
 
+        let theme = app.theme.clone()
+            .or_else(|| env::var("TOMLQ_THEME").ok())
+            .or_else(|| env::var("BAT_THEME").ok());
+
+        if let Some(theme) = theme {
+            pretty_printer.theme(theme);
+        }
+
         match app.output {
             Format::Toml => {
                 pretty_printer
@@ -113,12 +196,848 @@ fn main() -> anyhow::Result<()> {
                     .input_from_bytes(output.as_bytes())
                     .print()?;
             }
+
+            // Returned early above.
+            Format::Html => unreachable!(),
         }
     }
 
-    // If there is not syntax highlighting, just print normally.
-    #[cfg(not(feature = "syntax-highlighting"))]
+    // `lite-highlight` is the dependency-light alternative to `syntax-highlighting`, not a
+    // complement to it: when both features are enabled (e.g. `--all-features`), `bat`'s
+    // printer above already ran, so this only runs when `syntax-highlighting` is off.
+    #[cfg(all(feature = "lite-highlight", not(feature = "syntax-highlighting")))] {
+        let scheme = ColorScheme::resolve(&app.highlight);
+        println!("{}", render_lite(result, app.output, &scheme, app.pretty));
+    }
+
+    // If neither highlighting backend is enabled, just print normally.
+    #[cfg(not(any(feature = "syntax-highlighting", feature = "lite-highlight")))]
     println!("{output}");
 
     Ok(())
 }
+
+/// Prints a `toml::from_str` failure as the offending line plus a caret underneath,
+/// instead of letting the bare `anyhow` error bubble up with no context.
+fn report_parse_error(source: &str, err: &toml::de::Error) {
+    match err.span() {
+        Some(span) => print_annotated_snippet(source, span, &err.message().to_string()),
+        None => eprintln!("error: {err}"),
+    }
+}
+
+/// Prints an unresolved pattern (e.g. `servers.web.prot`) against the last table it
+/// did match, with a caret under the segment that doesn't exist there.
+///
+/// `tq::extract_pattern` itself only reports an opaque `anyhow` error today, so this
+/// re-walks `pattern` independently using plain dotted-key lookups to find the failing
+/// segment for display purposes. Its source isn't in this tree, so its full addressing
+/// grammar (e.g. whether it supports array indices like `servers.0.name`) is unknown here;
+/// this only commits to a diagnosis — "no such key" — when every segment up to the failure
+/// resolved as a table key, which it can verify by construction. The moment a segment would
+/// need anything other than a table-key lookup, it stops guessing and defers entirely to
+/// `extract_pattern`'s own message, rather than assert a "not a table" diagnosis that might
+/// be wrong about what `extract_pattern` actually supports.
+fn report_pattern_error(value: &Value, pattern: &str, err: &anyhow::Error) {
+    let segments: Vec<&str> = pattern.split('.').collect();
+    let mut current = value;
+
+    for (i, segment) in segments.iter().enumerate() {
+        let Value::Table(table) = current else {
+            break;
+        };
+
+        match table.get(*segment) {
+            Some(next) => current = next,
+            None => {
+                eprintln!("error: no key `{segment}` in `{}`", path_so_far(&segments[..i]));
+                print_pattern_caret(pattern, &segments[..i], segment);
+                return;
+            }
+        }
+    }
+
+    // Either every segment resolved as a table key and `extract_pattern` failed for some
+    // other reason this re-walk can't see, or a segment needed non-table addressing this
+    // re-walk doesn't understand; either way, defer to `extract_pattern`'s own message.
+    eprintln!("error: {err}");
+}
+
+fn path_so_far(segments: &[&str]) -> String {
+    if segments.is_empty() {
+        "<root>".to_string()
+    } else {
+        segments.join(".")
+    }
+}
+
+fn print_pattern_caret(pattern: &str, matched: &[&str], segment: &str) {
+    eprintln!();
+    eprintln!("    {pattern}");
+
+    let caret_offset: usize = matched.iter().map(|s| s.len() + 1).sum();
+    let caret = format!("{}{}", " ".repeat(caret_offset), "^".repeat(segment.len()));
+    eprintln!("    {}", dim(caret));
+}
+
+/// Prints `source` around `span` with 1-2 lines of context and a caret underline, e.g.:
+///
+/// ```text
+///    2 | prot = "https"
+///      |         ^^^^^^ invalid type: expected a key-value pair
+/// ```
+fn print_annotated_snippet(source: &str, span: std::ops::Range<usize>, message: &str) {
+    let (line_no, col_no) = locate(source, span.start);
+    let lines: Vec<&str> = source.lines().collect();
+    let line_idx = line_no - 1;
+
+    eprintln!("error: {message}");
+    eprintln!("  --> line {line_no}, column {col_no}");
+    eprintln!();
+
+    if line_idx > 0 {
+        if let Some(prev) = lines.get(line_idx - 1) {
+            eprintln!("{:>4} | {prev}", line_no - 1);
+        }
+    }
+
+    if let Some(line) = lines.get(line_idx) {
+        eprintln!("{:>4} | {line}", line_no);
+
+        let underline_len = (span.end - span.start)
+            .max(1)
+            .min(line.len().saturating_sub(col_no - 1).max(1));
+        let caret = format!("{}{}", " ".repeat(col_no - 1), "^".repeat(underline_len));
+        eprintln!("     | {} {message}", dim(caret));
+    }
+
+    if let Some(next) = lines.get(line_idx + 1) {
+        eprintln!("{:>4} | {next}", line_no + 1);
+    }
+}
+
+/// 1-based (line, column) of `byte_offset` within `source`.
+fn locate(source: &str, byte_offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut col = 1;
+    for (i, b) in source.bytes().enumerate() {
+        if i == byte_offset {
+            break;
+        }
+        if b == b'\n' {
+            line += 1;
+            col = 1;
+        } else {
+            col += 1;
+        }
+    }
+    (line, col)
+}
+
+/// Dims `text` when colors are enabled, matching the CLI's resolved color choice for
+/// *stderr* — `dim` is only ever used on the diagnostics this file writes with `eprintln!`,
+/// which must key off whether stderr (not stdout) is a terminal.
+fn dim(text: String) -> String {
+    if stderr_colors_enabled() {
+        format!("\x1b[2m{text}\x1b[0m")
+    } else {
+        text
+    }
+}
+
+static COLORS_ENABLED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+static STDERR_COLORS_ENABLED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+/// Whether *stdout* output should be colored, as resolved by `resolve_colors` at startup.
+/// This is the single source of truth for every stdout styling call site (the `--output`
+/// highlighters), whether or not `bat`/`console` are compiled in.
+fn colors_enabled() -> bool {
+    COLORS_ENABLED.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+fn set_colors_enabled(enabled: bool) {
+    COLORS_ENABLED.store(enabled, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Whether *stderr* diagnostics (the annotated-snippet carets) should be colored. Resolved
+/// separately from `colors_enabled` because piping stdout while stderr stays a TTY (or vice
+/// versa) means the two streams can legitimately disagree.
+fn stderr_colors_enabled() -> bool {
+    STDERR_COLORS_ENABLED.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+fn set_stderr_colors_enabled(enabled: bool) {
+    STDERR_COLORS_ENABLED.store(enabled, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Resolves `--color` together with `NO_COLOR`, `CLICOLOR`, and `CLICOLOR_FORCE` (as
+/// documented by the `colored`/`anstyle` ecosystem) into a single on/off decision for the
+/// given stream, so the choice doesn't depend on whether any particular highlighting
+/// backend is linked in.
+fn resolve_colors(flag: clap::ColorChoice, stream_is_terminal: bool) -> bool {
+    resolve_colors_from_env(
+        flag,
+        env::var_os("CLICOLOR_FORCE"),
+        env::var_os("NO_COLOR"),
+        env::var_os("CLICOLOR"),
+    )
+    .unwrap_or(stream_is_terminal)
+}
+
+/// The flag/env-var precedence behind `resolve_colors`, isolated from the TTY check so it
+/// can be unit-tested without a real terminal. Returns `None` when nothing settles the
+/// question, meaning the caller should fall back to checking whether its stream is a TTY.
+fn resolve_colors_from_env(
+    flag: clap::ColorChoice,
+    clicolor_force: Option<std::ffi::OsString>,
+    no_color: Option<std::ffi::OsString>,
+    clicolor: Option<std::ffi::OsString>,
+) -> Option<bool> {
+    match flag {
+        clap::ColorChoice::Always => return Some(true),
+        clap::ColorChoice::Never => return Some(false),
+        clap::ColorChoice::Auto => {}
+    }
+
+    if clicolor_force.is_some_and(|v| v != "0") {
+        return Some(true);
+    }
+    // Per the NO_COLOR spec (https://no-color.org/), only a *non-empty* value disables
+    // color; `NO_COLOR=` (set but empty) must not.
+    if no_color.is_some_and(|v| !v.is_empty()) {
+        return Some(false);
+    }
+    if clicolor.is_some_and(|v| v == "0") {
+        return Some(false);
+    }
+
+    None
+}
+
+/// Renders a resolved `toml::Value` as an HTML fragment, wrapping each token in a `<span>`
+/// so the output can be embedded in docs or styled with an external stylesheet, mirroring
+/// the `:html-inline` and `:html-classes` modes of a typical annotated-source renderer.
+fn render_html(value: &Value, markup: HtmlMarkup) -> String {
+    let mut out = String::new();
+    write_value_html(&mut out, value, markup, &mut Vec::new());
+    out
+}
+
+fn write_value_html(out: &mut String, value: &Value, markup: HtmlMarkup, path: &mut Vec<String>) {
+    match value {
+        Value::Table(table) => {
+            let mut scalars = Vec::new();
+            let mut tables = Vec::new();
+            let mut array_tables = Vec::new();
+
+            for (key, v) in table.iter() {
+                if matches!(v, Value::Table(_)) {
+                    tables.push((key, v));
+                } else if is_array_of_tables(v) {
+                    array_tables.push((key, v));
+                } else {
+                    scalars.push((key, v));
+                }
+            }
+
+            for (key, v) in scalars {
+                span(out, "key", key, markup);
+                out.push_str(" = ");
+                write_scalar_html(out, v, markup);
+                out.push('\n');
+            }
+
+            for (key, v) in tables {
+                path.push(key.clone());
+                out.push('[');
+                span(out, "table", &path.join("."), markup);
+                out.push_str("]\n");
+                write_value_html(out, v, markup, path);
+                path.pop();
+            }
+
+            for (key, v) in array_tables {
+                path.push(key.clone());
+                write_array_of_tables_html(out, v, markup, path);
+                path.pop();
+            }
+        }
+        other => write_scalar_html(out, other, markup),
+    }
+}
+
+/// Renders a `[[table]]` array: each element gets its own `[[path]]` header, rather than
+/// being nested inside the inline `[ … ]` brackets `write_scalar_html` uses for plain arrays.
+fn write_array_of_tables_html(out: &mut String, value: &Value, markup: HtmlMarkup, path: &mut Vec<String>) {
+    let Value::Array(items) = value else {
+        return write_scalar_html(out, value, markup);
+    };
+
+    for item in items {
+        out.push_str("[[");
+        span(out, "table", &path.join("."), markup);
+        out.push_str("]]\n");
+        write_value_html(out, item, markup, path);
+    }
+}
+
+fn write_scalar_html(out: &mut String, value: &Value, markup: HtmlMarkup) {
+    match value {
+        Value::String(s) => span(out, "string", &format!("\"{s}\""), markup),
+        Value::Integer(i) => span(out, "integer", &i.to_string(), markup),
+        Value::Float(f) => span(out, "float", &f.to_string(), markup),
+        Value::Boolean(b) => span(out, "boolean", &b.to_string(), markup),
+        Value::Datetime(d) => span(out, "datetime", &d.to_string(), markup),
+        Value::Array(items) if is_array_of_tables(value) => {
+            // A bare array-of-tables result (no enclosing key), e.g. querying `servers`
+            // directly: no `[[path]]` header makes sense, so just separate the tables.
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push('\n');
+                }
+                write_value_html(out, item, markup, &mut Vec::new());
+            }
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_scalar_html(out, item, markup);
+            }
+            out.push(']');
+        }
+        Value::Table(_) => write_value_html(out, value, markup, &mut Vec::new()),
+    }
+}
+
+/// Whether `value` is a non-empty array whose every element is a table, i.e. what TOML's
+/// `[[table]]` syntax produces. Such arrays need `[[path]]` headers per element rather than
+/// the inline `[ … ]` rendering used for plain (scalar) arrays.
+fn is_array_of_tables(value: &Value) -> bool {
+    matches!(value, Value::Array(items) if !items.is_empty() && items.iter().all(|i| matches!(i, Value::Table(_))))
+}
+
+/// Wraps `text` in a `<span>` for `class`, escaping it for HTML and, in `inline` mode,
+/// resolving `class` to a baked-in `style` attribute instead of a class name.
+fn span(out: &mut String, class: &str, text: &str, markup: HtmlMarkup) {
+    let escaped = escape_html(text);
+    match markup {
+        HtmlMarkup::Classes => out.push_str(&format!("<span class=\"{class}\">{escaped}</span>")),
+        HtmlMarkup::Inline => {
+            out.push_str(&format!("<span style=\"{}\">{escaped}</span>", span_style(class)))
+        }
+    }
+}
+
+/// The default inline style for each syntax element `render_html` can emit.
+fn span_style(class: &str) -> &'static str {
+    match class {
+        "key" => "color:#268bd2;font-weight:bold",
+        "string" => "color:#2aa198",
+        "integer" | "float" => "color:#d33682",
+        "boolean" => "color:#cb4b16",
+        "datetime" => "color:#6c71c4",
+        "table" => "color:#859900;font-weight:bold",
+        _ => "",
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    s.chars().fold(String::with_capacity(s.len()), |mut acc, c| {
+        match c {
+            '&' => acc.push_str("&amp;"),
+            '<' => acc.push_str("&lt;"),
+            '>' => acc.push_str("&gt;"),
+            '"' => acc.push_str("&quot;"),
+            '\'' => acc.push_str("&#39;"),
+            _ => acc.push(c),
+        }
+        acc
+    })
+}
+
+/// Renders `value` as `format`, token-colored straight from the `toml::Value` tree via
+/// `scheme`, rather than serializing first and re-lexing the text. `pretty` mirrors
+/// `--pretty`: it spreads array elements one per line instead of packing them inline.
+#[cfg(feature = "lite-highlight")]
+fn render_lite(value: &Value, format: Format, scheme: &ColorScheme, pretty: bool) -> String {
+    let mut out = String::new();
+    match format {
+        Format::Toml => write_toml_lite(&mut out, value, scheme, &mut Vec::new(), pretty),
+
+        #[cfg(feature = "json")]
+        Format::Json => write_json_lite(&mut out, value, scheme, 0, pretty),
+
+        // `main` returns early for HTML before calling this.
+        Format::Html => unreachable!(),
+    }
+    out
+}
+
+#[cfg(feature = "lite-highlight")]
+fn write_toml_lite(out: &mut String, value: &Value, scheme: &ColorScheme, path: &mut Vec<String>, pretty: bool) {
+    match value {
+        Value::Table(table) => {
+            let mut scalars = Vec::new();
+            let mut tables = Vec::new();
+            let mut array_tables = Vec::new();
+
+            for (key, v) in table.iter() {
+                if matches!(v, Value::Table(_)) {
+                    tables.push((key, v));
+                } else if is_array_of_tables(v) {
+                    array_tables.push((key, v));
+                } else {
+                    scalars.push((key, v));
+                }
+            }
+
+            for (key, v) in scalars {
+                out.push_str(&paint(key, scheme.style("key")));
+                out.push_str(" = ");
+                write_toml_scalar_lite(out, v, scheme, pretty);
+                out.push('\n');
+            }
+
+            for (key, v) in tables {
+                path.push(key.clone());
+                out.push('[');
+                out.push_str(&paint(&path.join("."), scheme.style("table")));
+                out.push_str("]\n");
+                write_toml_lite(out, v, scheme, path, pretty);
+                path.pop();
+            }
+
+            for (key, v) in array_tables {
+                path.push(key.clone());
+                write_array_of_tables_lite(out, v, scheme, path, pretty);
+                path.pop();
+            }
+        }
+        other => write_toml_scalar_lite(out, other, scheme, pretty),
+    }
+}
+
+/// Renders a `[[table]]` array: each element gets its own `[[path]]` header, rather than
+/// being nested inside the inline `[ … ]` brackets `write_toml_scalar_lite` uses for plain
+/// arrays.
+#[cfg(feature = "lite-highlight")]
+fn write_array_of_tables_lite(out: &mut String, value: &Value, scheme: &ColorScheme, path: &mut Vec<String>, pretty: bool) {
+    let Value::Array(items) = value else {
+        return write_toml_scalar_lite(out, value, scheme, pretty);
+    };
+
+    for item in items {
+        out.push_str("[[");
+        out.push_str(&paint(&path.join("."), scheme.style("table")));
+        out.push_str("]]\n");
+        write_toml_lite(out, item, scheme, path, pretty);
+    }
+}
+
+#[cfg(feature = "lite-highlight")]
+fn write_toml_scalar_lite(out: &mut String, value: &Value, scheme: &ColorScheme, pretty: bool) {
+    match value {
+        Value::String(s) => out.push_str(&paint(&format!("\"{s}\""), scheme.style("string"))),
+        Value::Integer(i) => out.push_str(&paint(&i.to_string(), scheme.style("integer"))),
+        Value::Float(f) => out.push_str(&paint(&f.to_string(), scheme.style("float"))),
+        Value::Boolean(b) => out.push_str(&paint(&b.to_string(), scheme.style("boolean"))),
+        Value::Datetime(d) => out.push_str(&paint(&d.to_string(), scheme.style("datetime"))),
+        Value::Array(items) if is_array_of_tables(value) => {
+            // A bare array-of-tables result (no enclosing key): no `[[path]]` header makes
+            // sense, so just separate the tables.
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push('\n');
+                }
+                write_toml_lite(out, item, scheme, &mut Vec::new(), pretty);
+            }
+        }
+        Value::Array(items) if pretty && !items.is_empty() => {
+            out.push_str("[\n");
+            for item in items {
+                out.push_str("    ");
+                write_toml_scalar_lite(out, item, scheme, pretty);
+                out.push_str(",\n");
+            }
+            out.push(']');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_toml_scalar_lite(out, item, scheme, pretty);
+            }
+            out.push(']');
+        }
+        Value::Table(_) => write_toml_lite(out, value, scheme, &mut Vec::new(), pretty),
+    }
+}
+
+#[cfg(all(feature = "lite-highlight", feature = "json"))]
+fn write_json_lite(out: &mut String, value: &Value, scheme: &ColorScheme, indent: usize, pretty: bool) {
+    match value {
+        Value::Table(table) if pretty => {
+            out.push_str("{\n");
+            let len = table.len();
+            for (i, (key, v)) in table.iter().enumerate() {
+                out.push_str(&"  ".repeat(indent + 1));
+                out.push_str(&paint(&format!("\"{key}\""), scheme.style("key")));
+                out.push_str(": ");
+                write_json_lite(out, v, scheme, indent + 1, pretty);
+                if i + 1 < len {
+                    out.push(',');
+                }
+                out.push('\n');
+            }
+            out.push_str(&"  ".repeat(indent));
+            out.push('}');
+        }
+        Value::Table(table) => {
+            out.push('{');
+            let len = table.len();
+            for (i, (key, v)) in table.iter().enumerate() {
+                out.push_str(&paint(&format!("\"{key}\""), scheme.style("key")));
+                out.push(':');
+                write_json_lite(out, v, scheme, indent, pretty);
+                if i + 1 < len {
+                    out.push(',');
+                }
+            }
+            out.push('}');
+        }
+        Value::Array(items) if pretty && !items.is_empty() => {
+            out.push_str("[\n");
+            let len = items.len();
+            for (i, item) in items.iter().enumerate() {
+                out.push_str(&"  ".repeat(indent + 1));
+                write_json_lite(out, item, scheme, indent + 1, pretty);
+                if i + 1 < len {
+                    out.push(',');
+                }
+                out.push('\n');
+            }
+            out.push_str(&"  ".repeat(indent));
+            out.push(']');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                write_json_lite(out, item, scheme, indent, pretty);
+            }
+            out.push(']');
+        }
+        Value::String(s) => out.push_str(&paint(&format!("\"{s}\""), scheme.style("string"))),
+        Value::Integer(i) => out.push_str(&paint(&i.to_string(), scheme.style("integer"))),
+        Value::Float(f) => out.push_str(&paint(&f.to_string(), scheme.style("float"))),
+        Value::Boolean(b) => out.push_str(&paint(&b.to_string(), scheme.style("boolean"))),
+        Value::Datetime(d) => out.push_str(&paint(&format!("\"{d}\""), scheme.style("datetime"))),
+    }
+}
+
+/// Wraps `text` in `style`'s ANSI escapes when colors are enabled, matching the CLI's
+/// resolved color choice.
+#[cfg(feature = "lite-highlight")]
+fn paint(text: &str, style: anstyle::Style) -> String {
+    if colors_enabled() {
+        format!("{}{text}{}", style.render(), style.render_reset())
+    } else {
+        text.to_string()
+    }
+}
+
+/// A per-element style table for the `lite-highlight` colorizer.
+#[cfg(feature = "lite-highlight")]
+struct ColorScheme {
+    key: anstyle::Style,
+    string: anstyle::Style,
+    integer: anstyle::Style,
+    float: anstyle::Style,
+    boolean: anstyle::Style,
+    datetime: anstyle::Style,
+    table: anstyle::Style,
+}
+
+#[cfg(feature = "lite-highlight")]
+impl Default for ColorScheme {
+    fn default() -> Self {
+        Self {
+            key: parse_style("bold fg:cyan"),
+            string: parse_style("fg:green"),
+            integer: parse_style("fg:purple"),
+            float: parse_style("fg:purple"),
+            boolean: parse_style("fg:yellow"),
+            datetime: parse_style("fg:blue"),
+            table: parse_style("bold fg:green"),
+        }
+    }
+}
+
+#[cfg(feature = "lite-highlight")]
+impl ColorScheme {
+    /// Builds a scheme from the built-in defaults, overridden by `TOMLQ_COLORS` and then
+    /// by `--highlight` flags, each a comma/repeat-separated list of `element=style` pairs.
+    fn resolve(highlight_flags: &[String]) -> Self {
+        let mut scheme = Self::default();
+
+        let from_env = env::var("TOMLQ_COLORS").unwrap_or_default();
+        let entries = from_env.split(',').filter(|s| !s.is_empty())
+            .chain(highlight_flags.iter().map(String::as_str));
+
+        for entry in entries {
+            let Some((element, style)) = entry.split_once('=') else {
+                continue;
+            };
+            let style = parse_style(style.trim());
+            match element.trim() {
+                "key" => scheme.key = style,
+                "string" => scheme.string = style,
+                "integer" => scheme.integer = style,
+                "float" => scheme.float = style,
+                "boolean" => scheme.boolean = style,
+                "datetime" => scheme.datetime = style,
+                "table" => scheme.table = style,
+                _ => {}
+            }
+        }
+
+        scheme
+    }
+
+    fn style(&self, element: &str) -> anstyle::Style {
+        match element {
+            "key" => self.key,
+            "string" => self.string,
+            "integer" => self.integer,
+            "float" => self.float,
+            "boolean" => self.boolean,
+            "datetime" => self.datetime,
+            "table" => self.table,
+            _ => anstyle::Style::new(),
+        }
+    }
+}
+
+/// Parses a starship-grammar style spec (e.g. `"bold fg:cyan"`) into an `anstyle::Style`.
+/// Unrecognized tokens are ignored rather than rejected, so a typo degrades gracefully.
+#[cfg(feature = "lite-highlight")]
+fn parse_style(spec: &str) -> anstyle::Style {
+    let mut style = anstyle::Style::new();
+
+    for token in spec.split_whitespace() {
+        if let Some(color) = token.strip_prefix("fg:") {
+            style = style.fg_color(parse_color(color));
+        } else if let Some(color) = token.strip_prefix("bg:") {
+            style = style.bg_color(parse_color(color));
+        } else {
+            style = match token {
+                "bold" => style.bold(),
+                "italic" => style.italic(),
+                "underline" => style.underline(),
+                "dimmed" | "dim" => style.dimmed(),
+                "none" => anstyle::Style::new(),
+                _ => style,
+            };
+        }
+    }
+
+    style
+}
+
+/// Parses a starship-grammar color: a named 16-color (plus `bright-` variants), a 0-255
+/// ANSI 256 index, or a `#rrggbb` hex triplet.
+#[cfg(feature = "lite-highlight")]
+fn parse_color(name: &str) -> Option<anstyle::Color> {
+    use anstyle::{Ansi256Color, AnsiColor, Color, RgbColor};
+
+    if let Some(hex) = name.strip_prefix('#') {
+        if hex.len() == 6 {
+            let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+            let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+            let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+            return Some(Color::Rgb(RgbColor(r, g, b)));
+        }
+    }
+
+    if let Ok(index) = name.parse::<u8>() {
+        return Some(Color::Ansi256(Ansi256Color(index)));
+    }
+
+    let ansi = match name {
+        "black" => AnsiColor::Black,
+        "red" => AnsiColor::Red,
+        "green" => AnsiColor::Green,
+        "yellow" => AnsiColor::Yellow,
+        "blue" => AnsiColor::Blue,
+        "purple" | "magenta" => AnsiColor::Magenta,
+        "cyan" => AnsiColor::Cyan,
+        "white" => AnsiColor::White,
+        "bright-black" => AnsiColor::BrightBlack,
+        "bright-red" => AnsiColor::BrightRed,
+        "bright-green" => AnsiColor::BrightGreen,
+        "bright-yellow" => AnsiColor::BrightYellow,
+        "bright-blue" => AnsiColor::BrightBlue,
+        "bright-purple" | "bright-magenta" => AnsiColor::BrightMagenta,
+        "bright-cyan" => AnsiColor::BrightCyan,
+        "bright-white" => AnsiColor::BrightWhite,
+        _ => return None,
+    };
+    Some(Color::Ansi(ansi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_finds_line_and_column() {
+        let source = "a = 1\nb = 2\nc = 3\n";
+        assert_eq!(locate(source, 0), (1, 1));
+        assert_eq!(locate(source, 4), (1, 5));
+        assert_eq!(locate(source, 6), (2, 1));
+        assert_eq!(locate(source, 12), (3, 1));
+    }
+
+    #[test]
+    fn path_so_far_renders_root_and_dotted_paths() {
+        assert_eq!(path_so_far(&[]), "<root>");
+        assert_eq!(path_so_far(&["servers", "web"]), "servers.web");
+    }
+
+    #[test]
+    fn resolve_colors_from_env_flag_overrides_everything() {
+        let none = || None;
+        assert_eq!(
+            resolve_colors_from_env(clap::ColorChoice::Always, none(), Some("1".into()), none()),
+            Some(true)
+        );
+        assert_eq!(
+            resolve_colors_from_env(clap::ColorChoice::Never, none(), none(), none()),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn resolve_colors_from_env_empty_no_color_does_not_disable() {
+        // `NO_COLOR=` (present but empty) must not disable color, per the NO_COLOR spec.
+        assert_eq!(
+            resolve_colors_from_env(clap::ColorChoice::Auto, None, Some("".into()), None),
+            None
+        );
+        assert_eq!(
+            resolve_colors_from_env(clap::ColorChoice::Auto, None, Some("1".into()), None),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn resolve_colors_from_env_precedence() {
+        // CLICOLOR_FORCE wins over NO_COLOR.
+        assert_eq!(
+            resolve_colors_from_env(
+                clap::ColorChoice::Auto,
+                Some("1".into()),
+                Some("1".into()),
+                None
+            ),
+            Some(true)
+        );
+        // CLICOLOR=0 only matters once NO_COLOR/CLICOLOR_FORCE don't decide.
+        assert_eq!(
+            resolve_colors_from_env(clap::ColorChoice::Auto, None, None, Some("0".into())),
+            Some(false)
+        );
+        // Nothing set: defer to the TTY check.
+        assert_eq!(
+            resolve_colors_from_env(clap::ColorChoice::Auto, None, None, None),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "lite-highlight")]
+    fn parse_color_handles_named_hex_and_256_variants() {
+        use anstyle::{Ansi256Color, AnsiColor, Color, RgbColor};
+
+        assert_eq!(parse_color("cyan"), Some(Color::Ansi(AnsiColor::Cyan)));
+        assert_eq!(
+            parse_color("bright-red"),
+            Some(Color::Ansi(AnsiColor::BrightRed))
+        );
+        assert_eq!(
+            parse_color("#ff00aa"),
+            Some(Color::Rgb(RgbColor(0xff, 0x00, 0xaa)))
+        );
+        assert_eq!(parse_color("200"), Some(Color::Ansi256(Ansi256Color(200))));
+        assert_eq!(parse_color("not-a-color"), None);
+        assert_eq!(parse_color("#zzzzzz"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "lite-highlight")]
+    fn parse_style_combines_modifiers_and_colors() {
+        let style = parse_style("bold fg:cyan bg:#112233");
+
+        assert_eq!(style, anstyle::Style::new().bold().fg_color(parse_color("cyan")).bg_color(parse_color("#112233")));
+    }
+
+    #[test]
+    #[cfg(feature = "lite-highlight")]
+    fn parse_style_ignores_unknown_tokens() {
+        assert_eq!(parse_style("not-a-real-token"), anstyle::Style::new());
+    }
+
+    #[test]
+    fn escape_html_escapes_all_five_entities() {
+        assert_eq!(
+            escape_html(r#"<a href="x">it's & "that"</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;it&#39;s &amp; &quot;that&quot;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn escape_html_passes_through_plain_text() {
+        assert_eq!(escape_html("plain text 123"), "plain text 123");
+    }
+
+    #[test]
+    fn render_html_array_of_tables_gets_one_block_per_element() {
+        let toml_value: Value = toml::from_str(
+            r#"
+            [[servers]]
+            name = "a"
+
+            [[servers]]
+            name = "b"
+            "#,
+        )
+        .unwrap();
+
+        let html = render_html(&toml_value, HtmlMarkup::Classes);
+
+        // Each element is its own `[[servers]]` block, not one nested inside `[ ... ]`.
+        assert_eq!(html.matches("[[").count(), 2);
+        assert_eq!(html.matches("]]").count(), 2);
+        assert!(!html.contains(" = ["));
+        assert!(html.contains(r#"<span class="string">&quot;a&quot;</span>"#));
+        assert!(html.contains(r#"<span class="string">&quot;b&quot;</span>"#));
+    }
+
+    #[test]
+    fn is_array_of_tables_distinguishes_from_plain_arrays() {
+        let array_of_tables: Value = toml::from_str("x = [{a = 1}, {a = 2}]").unwrap();
+        let plain_array: Value = toml::from_str("x = [1, 2, 3]").unwrap();
+        let empty_array: Value = toml::from_str("x = []").unwrap();
+
+        assert!(is_array_of_tables(array_of_tables.get("x").unwrap()));
+        assert!(!is_array_of_tables(plain_array.get("x").unwrap()));
+        assert!(!is_array_of_tables(empty_array.get("x").unwrap()));
+    }
+}